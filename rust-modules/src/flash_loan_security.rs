@@ -40,6 +40,7 @@ pub struct FlashLoanSecurityEngine {
     pub active_loans: HashMap<String, FlashLoanRequest>,
     pub execution_stats: ExecutionStats,
     pub security_config: SecurityConfig,
+    pub oracle_chains: HashMap<Pubkey, OracleChain>,
 }
 
 #[derive(Debug, Default)]
@@ -61,6 +62,12 @@ pub struct SecurityConfig {
     pub emergency_stop_enabled: bool,
     pub require_multi_sig: bool,
     pub approved_providers: Vec<String>,
+    /// Maximum age of an oracle publish slot, relative to the signal's
+    /// `signal_slot`, before that oracle is considered stale.
+    pub max_oracle_staleness_slots: u64,
+    /// Maximum oracle confidence interval, expressed in basis points of the
+    /// oracle price, before that oracle is considered too uncertain to act on.
+    pub max_oracle_confidence_bps: u64,
 }
 
 impl Default for SecurityConfig {
@@ -77,6 +84,8 @@ impl Default for SecurityConfig {
                 "marginfi".to_string(),
                 "jupiter".to_string(),
             ],
+            max_oracle_staleness_slots: 150, // ~60s at ~400ms/slot
+            max_oracle_confidence_bps: 100,  // 1% of price
         }
     }
 }
@@ -87,6 +96,7 @@ impl FlashLoanSecurityEngine {
             active_loans: HashMap::new(),
             execution_stats: ExecutionStats::default(),
             security_config: SecurityConfig::default(),
+            oracle_chains: HashMap::new(),
         }
     }
 
@@ -333,6 +343,28 @@ pub struct SystemHealth {
     pub last_execution: Option<Instant>,
 }
 
+// 🔮 ORACLE FALLBACK CHAIN
+//
+// A single price observation from one oracle source. `publish_slot` and
+// `confidence_interval` are both required to judge whether the price is
+// still safe to trade on.
+#[derive(Debug, Clone)]
+pub struct OraclePrice {
+    pub source: String,
+    pub price: u64,
+    pub confidence_interval: u64,
+    pub publish_slot: u64,
+}
+
+/// Primary + fallback oracle sources for a single token. `sources[0]` is the
+/// primary oracle; the rest are tried in order if it is stale or too
+/// uncertain to trust.
+#[derive(Debug, Clone)]
+pub struct OracleChain {
+    pub token_mint: Pubkey,
+    pub sources: Vec<OraclePrice>,
+}
+
 // 💎 INTEGRACJA Z MOJO INTELLIGENCE
 #[derive(Debug)]
 pub struct MojoSignal {
@@ -340,15 +372,60 @@ pub struct MojoSignal {
     pub confidence: f32,
     pub expected_profit: u64,
     pub risk_score: f32,
-    pub timestamp: i64,
+    /// Solana slot at which Mojo observed the opportunity, in the same unit
+    /// as `OraclePrice::publish_slot` — used to gate oracle staleness.
+    pub signal_slot: i64,
+    /// Size of the opportunity Mojo detected, in base units of `token_mint`.
+    /// Used to recompute `expected_profit` from a trusted oracle price
+    /// instead of acting on the value attached to the signal.
+    pub token_amount: u64,
+    /// Price Mojo's strategy claims it can execute the trade at (e.g. a DEX
+    /// quote), in the same unit as `OraclePrice::price`. The spread between
+    /// this and the oracle price is what actually determines profit.
+    pub claimed_execution_price: u64,
 }
 
 impl FlashLoanSecurityEngine {
+    /// Registers (or replaces) the oracle fallback chain used to price a token.
+    pub fn register_oracle_chain(&mut self, chain: OracleChain) {
+        msg!("🔧 Registered oracle chain for {} ({} sources)", chain.token_mint, chain.sources.len());
+        self.oracle_chains.insert(chain.token_mint, chain);
+    }
+
+    // 🔮 WYBÓR CENY Z ŁAŃCUCHA ORACLE
+    //
+    // Walks the chain in order, skipping any source whose publish slot is
+    // stale relative to `signal_slot` or whose confidence interval is
+    // too wide relative to its price. Returns the first usable price, or a
+    // dedicated error if every source in the chain is unusable.
+    fn resolve_oracle_price(&self, chain: &OracleChain, signal_slot: i64) -> Result<OraclePrice, ProgramError> {
+        for candidate in &chain.sources {
+            let age_slots = signal_slot.saturating_sub(candidate.publish_slot as i64);
+            if age_slots > self.security_config.max_oracle_staleness_slots as i64 {
+                msg!("⚠️ Oracle {} stale: {} slots old", candidate.source, age_slots);
+                continue;
+            }
+
+            let confidence_bps = candidate.confidence_interval
+                .checked_mul(10_000)
+                .and_then(|v| v.checked_div(candidate.price.max(1)))
+                .unwrap_or(u64::MAX);
+            if confidence_bps > self.security_config.max_oracle_confidence_bps {
+                msg!("⚠️ Oracle {} confidence band too wide: {} bps", candidate.source, confidence_bps);
+                continue;
+            }
+
+            return Ok(candidate.clone());
+        }
+
+        msg!("❌ No usable oracle price: every source stale or too uncertain");
+        Err(ProgramError::Custom(13))
+    }
+
     pub fn execute_mojo_signal(&mut self, signal: MojoSignal) -> Result<u64, ProgramError> {
         msg!("🔥 Executing Mojo intelligence signal");
         msg!("   Token: {}", signal.token_mint);
         msg!("   Confidence: {:.2}%", signal.confidence * 100.0);
-        msg!("   Expected profit: {} lamports", signal.expected_profit);
         msg!("   Risk score: {:.2}", signal.risk_score);
 
         // Sprawdź czy sygnał jest wystarczająco dobry
@@ -357,15 +434,50 @@ impl FlashLoanSecurityEngine {
             return Err(ProgramError::Custom(11));
         }
 
-        if signal.expected_profit < self.security_config.min_profit_threshold {
-            msg!("❌ Expected profit too low: {}", signal.expected_profit);
+        // Nigdy nie ufaj signal.expected_profit — wycenia je oracle
+        let chain = self.oracle_chains.get(&signal.token_mint)
+            .ok_or(ProgramError::Custom(14))?
+            .clone();
+        let oracle_price = self.resolve_oracle_price(&chain, signal.signal_slot)?;
+
+        // signal.claimed_execution_price and signal.token_amount are still
+        // Mojo-supplied and untrusted, so bound both against the oracle
+        // before using them rather than taking them at face value.
+        let band_low = oracle_price.price.saturating_sub(oracle_price.confidence_interval);
+        let band_high = oracle_price.price.saturating_add(oracle_price.confidence_interval);
+        if signal.claimed_execution_price < band_low || signal.claimed_execution_price > band_high {
+            msg!("❌ Claimed execution price {} outside oracle confidence band [{}, {}]",
+                signal.claimed_execution_price, band_low, band_high);
+            return Err(ProgramError::Custom(16));
+        }
+
+        let notional = oracle_price.price
+            .checked_mul(signal.token_amount)
+            .ok_or(ProgramError::Custom(17))?;
+        if notional > self.security_config.max_flash_loan_amount {
+            msg!("❌ Claimed trade notional {} exceeds max flash loan amount {}", notional, self.security_config.max_flash_loan_amount);
+            return Err(ProgramError::Custom(17));
+        }
+
+        // Profit is the spread between the trusted oracle price and the price
+        // Mojo claims it can execute at, not the oracle price itself — and
+        // that spread is now capped at the oracle's own confidence interval.
+        let spread_per_unit = oracle_price.price.abs_diff(signal.claimed_execution_price);
+        let expected_profit = spread_per_unit
+            .checked_mul(signal.token_amount)
+            .ok_or(ProgramError::Custom(15))?;
+        msg!("   Oracle: {} @ {} lamports, claimed execution {} lamports (recomputed profit: {})",
+            oracle_price.source, oracle_price.price, signal.claimed_execution_price, expected_profit);
+
+        if expected_profit < self.security_config.min_profit_threshold {
+            msg!("❌ Expected profit too low: {}", expected_profit);
             return Err(ProgramError::Custom(12));
         }
 
         // Stwórz flash loan request na podstawie sygnału Mojo
         let request = FlashLoanRequest {
             user: Pubkey::new_unique(), // Would be actual user
-            amount: signal.expected_profit.checked_mul(10).unwrap(), // 10x profit as loan
+            amount: expected_profit.checked_mul(10).unwrap(), // 10x profit as loan
             provider: "solend".to_string(), // Choose best provider
             instructions: vec![], // Would contain actual DEX instructions
             timeout: Duration::from_secs(10),
@@ -412,6 +524,130 @@ mod tests {
         engine.resume_operations();
         // Would succeed with proper implementation
     }
+
+    #[test]
+    fn test_oracle_falls_back_when_primary_is_stale() {
+        let mut engine = FlashLoanSecurityEngine::new();
+        let token_mint = Pubkey::new_unique();
+        engine.register_oracle_chain(OracleChain {
+            token_mint,
+            sources: vec![
+                OraclePrice {
+                    source: "stale_primary".to_string(),
+                    price: 100,
+                    confidence_interval: 1,
+                    publish_slot: 0,
+                },
+                OraclePrice {
+                    source: "fresh_fallback".to_string(),
+                    price: 200,
+                    confidence_interval: 1,
+                    publish_slot: 1_000,
+                },
+            ],
+        });
+
+        let chain = engine.oracle_chains.get(&token_mint).unwrap().clone();
+        let resolved = engine.resolve_oracle_price(&chain, 1_000).unwrap();
+        assert_eq!(resolved.source, "fresh_fallback");
+    }
+
+    #[test]
+    fn test_oracle_signal_rejected_when_all_sources_stale() {
+        let mut engine = FlashLoanSecurityEngine::new();
+        let token_mint = Pubkey::new_unique();
+        engine.register_oracle_chain(OracleChain {
+            token_mint,
+            sources: vec![OraclePrice {
+                source: "pyth".to_string(),
+                price: 100,
+                confidence_interval: 1,
+                publish_slot: 0,
+            }],
+        });
+
+        let signal = MojoSignal {
+            token_mint,
+            confidence: 0.9,
+            expected_profit: 999_999_999, // should be ignored entirely
+            risk_score: 0.1,
+            signal_slot: 10_000, // far beyond max_oracle_staleness_slots
+            token_amount: 1_000,
+            claimed_execution_price: 90,
+        };
+
+        assert!(engine.execute_mojo_signal(signal).is_err());
+    }
+
+    #[test]
+    fn test_oracle_signal_rejected_without_registered_chain() {
+        let mut engine = FlashLoanSecurityEngine::new();
+        let signal = MojoSignal {
+            token_mint: Pubkey::new_unique(),
+            confidence: 0.9,
+            expected_profit: 999_999_999,
+            risk_score: 0.1,
+            signal_slot: 0,
+            token_amount: 1_000,
+            claimed_execution_price: 90,
+        };
+
+        assert!(engine.execute_mojo_signal(signal).is_err());
+    }
+
+    #[test]
+    fn test_oracle_falls_back_when_primary_confidence_too_wide() {
+        let mut engine = FlashLoanSecurityEngine::new();
+        let token_mint = Pubkey::new_unique();
+        engine.register_oracle_chain(OracleChain {
+            token_mint,
+            sources: vec![
+                OraclePrice {
+                    source: "uncertain_primary".to_string(),
+                    price: 100,
+                    confidence_interval: 50, // 5000 bps, way over the 100 bps default
+                    publish_slot: 0,
+                },
+                OraclePrice {
+                    source: "confident_fallback".to_string(),
+                    price: 100,
+                    confidence_interval: 1, // 100 bps, right at the default limit
+                    publish_slot: 0,
+                },
+            ],
+        });
+
+        let chain = engine.oracle_chains.get(&token_mint).unwrap().clone();
+        let resolved = engine.resolve_oracle_price(&chain, 0).unwrap();
+        assert_eq!(resolved.source, "confident_fallback");
+    }
+
+    #[test]
+    fn test_oracle_signal_rejected_when_all_sources_too_uncertain() {
+        let mut engine = FlashLoanSecurityEngine::new();
+        let token_mint = Pubkey::new_unique();
+        engine.register_oracle_chain(OracleChain {
+            token_mint,
+            sources: vec![OraclePrice {
+                source: "pyth".to_string(),
+                price: 100,
+                confidence_interval: 50, // 5000 bps, way over the 100 bps default
+                publish_slot: 0,
+            }],
+        });
+
+        let signal = MojoSignal {
+            token_mint,
+            confidence: 0.9,
+            expected_profit: 999_999_999,
+            risk_score: 0.1,
+            signal_slot: 0,
+            token_amount: 1_000,
+            claimed_execution_price: 90,
+        };
+
+        assert!(engine.execute_mojo_signal(signal).is_err());
+    }
 }
 
 // 🚀 GŁÓWNA FUNKCJA MODUŁU
@@ -420,12 +656,25 @@ pub extern "C" fn flash_loan_security_entry() -> u64 {
     let mut engine = FlashLoanSecurityEngine::new();
 
     // Przykładowe wykonanie
+    let token_mint = Pubkey::new_unique();
+    engine.register_oracle_chain(OracleChain {
+        token_mint,
+        sources: vec![OraclePrice {
+            source: "pyth".to_string(),
+            price: 150,
+            confidence_interval: 1,
+            publish_slot: 100,
+        }],
+    });
+
     let sample_signal = MojoSignal {
-        token_mint: Pubkey::new_unique(),
+        token_mint,
         confidence: 0.85,
-        expected_profit: 15_000_000, // 0.015 SOL
+        expected_profit: 15_000_000, // 0.015 SOL (untrusted, recomputed from oracle)
         risk_score: 0.2,
-        timestamp: 0,
+        signal_slot: 100,
+        token_amount: 100_000,
+        claimed_execution_price: 149,
     };
 
     match engine.execute_mojo_signal(sample_signal) {